@@ -1,11 +1,15 @@
 // Copyright 2024 Felipe Torres González
 
 mod photo_file;
+mod photo_library;
+mod raw_registry;
+mod signature;
 
 pub use crate::photo_file::*;
+pub use crate::photo_library::*;
 
 /// Enum that indicates whether a file is a raw photo, a developed photo or metadata.
-pub enum FileType {
+pub enum PhotoType {
     Raw,
     Img,
     Other,
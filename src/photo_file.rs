@@ -1,4 +1,49 @@
 use super::PhotoType;
+use crate::raw_registry::{mime_for_extension, vendor_for_extension};
+use sha2::{Digest, Sha256};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Size of the chunks used to stream a file while computing its digest in [`PhotoFile::digest`].
+const DIGEST_CHUNK_SIZE: usize = 64 * 1024;
+
+/// `errno` value for a cross-device link, returned by `rename(2)` when source and destination
+/// live on different file systems. Used by [`PhotoFile::relocate`] to detect when it must fall
+/// back to a copy-then-delete move.
+#[cfg(unix)]
+const EXDEV: i32 = 18;
+
+/// Returns `true` when `err` is the cross-device-link error raised by a failed `rename`.
+fn is_cross_device_error(err: &std::io::Error) -> bool {
+    #[cfg(unix)]
+    {
+        err.raw_os_error() == Some(EXDEV)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = err;
+        false
+    }
+}
+
+/// Extensions recognised as a developed (non-raw) image, independent of any particular
+/// `PhotoFile`'s configured `img_ext`. Used by [`PhotoFile::mark_found`] so that, e.g., a
+/// `.jpeg` file is matched even though the default `img_ext` is `JPG`.
+const IMG_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "tif", "tiff"];
+
+/// Extensions recognised as sidecar/metadata files, independent of any particular
+/// `PhotoFile`'s configured `other_ext`.
+const OTHER_EXTENSIONS: &[&str] = &["xmp", "pp3", "dop"];
+
+/// Returns `true` when `ext` is a known developed-image extension (case-insensitive).
+fn is_img_extension(ext: &str) -> bool {
+    IMG_EXTENSIONS.iter().any(|known| known.eq_ignore_ascii_case(ext))
+}
+
+/// Returns `true` when `ext` is a known sidecar extension (case-insensitive).
+fn is_other_extension(ext: &str) -> bool {
+    OTHER_EXTENSIONS.iter().any(|known| known.eq_ignore_ascii_case(ext))
+}
 
 /// Groups whether a photo has raw file, developed file or extra files linked to it.
 #[derive(Debug)]
@@ -11,13 +56,11 @@ struct HashType {
 impl HashType {
     /// Function that initialises a `HashType` struct to all fields false.
     fn new() -> HashType {
-        let ht = HashType {
+        HashType {
             hash_raw: false,
             hash_img: false,
             hash_other: false,
-        };
-
-        ht
+        }
     }
 }
 
@@ -37,6 +80,9 @@ pub struct PhotoFile {
     raw_ext: String,
     img_ext: String,
     other_ext: String,
+    /// Camera vendor inferred from `raw_ext`, when it is one of the extensions known to
+    /// [`crate::raw_registry`].
+    vendor: Option<&'static str>,
 }
 
 impl PhotoFile {
@@ -59,11 +105,11 @@ impl PhotoFile {
     ///
     /// - _name_: a string slice that indicates the name of the picture (with no extension).
     /// - _raw_ext_: a wrapped string slice. When None is passed, the default choice (_RAF_) is
-    ///              applied.
+    ///   applied.
     /// - _img_ext_: a wrapped string slice. When None is passed, the default choice (_JPG_) is
-    ///              applied.
+    ///   applied.
     /// - _other_ext_: a wrapped string slice. When None is passed, the default choice (_xmp_) is
-    ///              applied.
+    ///   applied.
     ///
     /// # Example of use
     ///
@@ -88,36 +134,76 @@ impl PhotoFile {
             img_ext: Option<&str>,
             other_ext: Option<&str>
     ) -> PhotoFile {
-        let rext = if raw_ext.is_some() {
-            String::from(raw_ext.unwrap())
-        } else {
-            String::from("RAF")
+        let rext = match raw_ext {
+            Some(raw_ext) => String::from(raw_ext),
+            None => String::from("RAF"),
         };
 
-        let iext = if img_ext.is_some() {
-            String::from(img_ext.unwrap())
-        } else {
-            String::from("JPG")
+        let iext = match img_ext {
+            Some(img_ext) => String::from(img_ext),
+            None => String::from("JPG"),
         };
 
-        let oext = if other_ext.is_some() {
-            String::from(other_ext.unwrap())
-        } else {
-            String::from("xmp")
+        let oext = match other_ext {
+            Some(other_ext) => String::from(other_ext),
+            None => String::from("xmp"),
         };
 
-        let pf = PhotoFile {
+        let vendor = vendor_for_extension(&rext);
+
+        PhotoFile {
             name: String::from(name),
             types_found: HashType::new(),
             raw_ext: rext,
             img_ext: iext,
             other_ext: oext,
-        };
-
-        pf
+            vendor,
+        }
     }
 
+    /// Builds a **PhotoFile** by inferring its raw extension (and vendor) from a file on disk.
+    ///
+    /// # Description
+    ///
+    /// Looks up `path`'s extension in the raw extension registry. This lets callers point the
+    /// library at a raw file without knowing ahead of time which camera manufacturer produced
+    /// it. The developed and other extensions fall back to the usual defaults (_JPG_ and
+    /// _xmp_).
+    ///
+    /// Returns `None` when `path` has no extension, the extension is not valid UTF-8, or the
+    /// extension is not a known raw extension.
+    ///
+    /// # Example of use
+    ///
+    /// ```rust,no_run
+    /// use photo_lib::PhotoFile;
     ///
+    /// let image = PhotoFile::from_raw_path("/home/user/pictures/DSCF1022.RAF").unwrap();
+    /// assert_eq!(image.vendor(), Some("Fujifilm"));
+    /// ```
+    pub fn from_raw_path<P: AsRef<Path>>(path: P) -> Option<PhotoFile> {
+        let path = path.as_ref();
+        let ext = path.extension()?.to_str()?;
+
+        vendor_for_extension(ext)?;
+
+        // Keep the directory in the bundle's name, same as `PhotoLibrary::scan`, so that later
+        // operations like `digest` and `clear` resolve the file regardless of the process's
+        // current directory.
+        let stem = path.with_extension("").to_string_lossy().to_string();
+
+        let mut pf = PhotoFile::new(&stem, Some(ext), None, None);
+        pf.hash_raw(Some(true));
+
+        Some(pf)
+    }
+
+    /// Returns the camera vendor inferred for this photo's raw extension, if known.
+    pub fn vendor(&self) -> Option<&'static str> {
+        self.vendor
+    }
+
+    /// Gets or sets whether the raw file for this photo has been found.
     pub fn hash_raw(&mut self, raw_exists: Option<bool>) -> bool {
         if let Some(re) = raw_exists {
             self.types_found.hash_raw = re;
@@ -126,7 +212,7 @@ impl PhotoFile {
         self.types_found.hash_raw
     }
 
-    ///
+    /// Gets or sets whether the developed file for this photo has been found.
     pub fn hash_img(&mut self, img_exists: Option<bool>) -> bool {
         if let Some(ie) = img_exists {
             self.types_found.hash_img = ie;
@@ -135,7 +221,7 @@ impl PhotoFile {
         self.types_found.hash_img
     }
 
-    ///
+    /// Gets or sets whether the other (sidecar) file for this photo has been found.
     pub fn hash_other(&mut self, other_exists: Option<bool>) -> bool {
         if let Some(oe) = other_exists {
             self.types_found.hash_other = oe;
@@ -144,14 +230,61 @@ impl PhotoFile {
         self.types_found.hash_other
     }
 
-    pub fn is_developed(&self) -> bool {
-        if self.types_found.hash_raw && self.types_found.hash_img {
-            true
+    /// Records that the file carrying extension `ext` was found for this bundle.
+    ///
+    /// # Description
+    ///
+    /// Classifies `ext`, case-insensitively, against:
+    /// - the raw extension registry (see [`crate::raw_registry`]), covering every vendor it
+    ///   knows about, not just this bundle's configured `raw_ext`;
+    /// - the set of known developed-image extensions (`jpg`, `jpeg`, `png`, `tif`, `tiff`);
+    /// - the set of known sidecar extensions (`xmp`, `pp3`, `dop`);
+    ///
+    /// falling back to this bundle's own configured `raw_ext`/`img_ext`/`other_ext` so a
+    /// custom extension passed to [`PhotoFile::new`] still matches. On a match, flips the
+    /// corresponding `types_found` entry to `true` and updates the stored extension (and, for
+    /// a raw match, the vendor) to what was actually found on disk, so that a later
+    /// [`PhotoFile::clear`] builds a path that matches the file (e.g. a bundle defaulting to
+    /// `RAF` must still be able to delete a file that was found as `.raf`, or as `.CR2`). Used
+    /// by [`crate::PhotoLibrary::scan`] while grouping files by stem.
+    ///
+    /// Returns the [`PhotoType`] that was recorded, or `None` if `ext` does not match any of
+    /// the known or configured extensions.
+    pub(crate) fn mark_found(&mut self, ext: &str) -> Option<PhotoType> {
+        if let Some(vendor) = vendor_for_extension(ext) {
+            self.raw_ext = String::from(ext);
+            self.vendor = Some(vendor);
+            self.hash_raw(Some(true));
+            Some(PhotoType::Raw)
+        } else if is_img_extension(ext) {
+            self.img_ext = String::from(ext);
+            self.hash_img(Some(true));
+            Some(PhotoType::Img)
+        } else if is_other_extension(ext) {
+            self.other_ext = String::from(ext);
+            self.hash_other(Some(true));
+            Some(PhotoType::Other)
+        } else if ext.eq_ignore_ascii_case(&self.raw_ext) {
+            self.raw_ext = String::from(ext);
+            self.hash_raw(Some(true));
+            Some(PhotoType::Raw)
+        } else if ext.eq_ignore_ascii_case(&self.img_ext) {
+            self.img_ext = String::from(ext);
+            self.hash_img(Some(true));
+            Some(PhotoType::Img)
+        } else if ext.eq_ignore_ascii_case(&self.other_ext) {
+            self.other_ext = String::from(ext);
+            self.hash_other(Some(true));
+            Some(PhotoType::Other)
         } else {
-            false
+            None
         }
     }
 
+    pub fn is_developed(&self) -> bool {
+        self.types_found.hash_raw && self.types_found.hash_img
+    }
+
     /// Delete one of the associated files to a photo instance.
     ///
     /// # Description
@@ -178,6 +311,20 @@ impl PhotoFile {
     /// }
     /// ```
     pub fn clear(&mut self, image_type: PhotoType) -> std::io::Result<u32> {
+        let (filepath, exists) = self.component_path(&image_type);
+
+        if !exists {
+            Err(std::io::Error::from(std::io::ErrorKind::NotFound))
+        } else {
+            std::fs::remove_file(filepath)?;
+            Ok(0)
+        }
+    }
+
+    /// Builds the file path for the component indicated by `image_type`, along with whether
+    /// that component was actually found for this photo. Shared by [`PhotoFile::clear`] and
+    /// [`PhotoFile::digest`].
+    fn component_path(&self, image_type: &PhotoType) -> (String, bool) {
         let mut filepath = self.name.clone();
 
         let (extra, exists) = match image_type {
@@ -186,20 +333,234 @@ impl PhotoFile {
             PhotoType::Other => (self.other_ext.as_str(), self.types_found.hash_other),
         };
 
+        filepath.push('.');
         filepath.push_str(extra);
 
+        (filepath, exists)
+    }
+
+    /// Computes the SHA-256 digest of the file component indicated by `image_type`.
+    ///
+    /// # Description
+    ///
+    /// Streams the file in fixed-size chunks rather than loading it whole, since raw files can
+    /// be large, and returns the digest as a lower-case hex string. If the requested component
+    /// was not found for this photo, returns an `std::io::ErrorKind::NotFound` error.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use photo_lib::*;
+    ///
+    /// let mut image = PhotoFile::new("myname", Some("dng"), Some("jpg"), None);
+    /// image.hash_raw(Some(true));
+    /// let digest = image.digest(PhotoType::Raw).unwrap();
+    /// println!("{digest}");
+    /// ```
+    pub fn digest(&self, image_type: PhotoType) -> std::io::Result<String> {
+        let (filepath, exists) = self.component_path(&image_type);
+
         if !exists {
-            Err(std::io::Error::from(std::io::ErrorKind::NotFound))
+            return Err(std::io::Error::from(std::io::ErrorKind::NotFound));
+        }
+
+        let mut file = std::fs::File::open(filepath)?;
+        let mut hasher = Sha256::new();
+        let mut buf = [0u8; DIGEST_CHUNK_SIZE];
+
+        loop {
+            let read = file.read(&mut buf)?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buf[..read]);
+        }
+
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+
+    /// Reports the MIME type of the component indicated by `image_type`.
+    ///
+    /// # Description
+    ///
+    /// Backed by the same vendor/extension registry used by [`PhotoFile::from_raw_path`], so
+    /// the MIME mapping stays consistent with extension-based vendor detection: a known raw
+    /// extension reports its vendor-specific MIME type (e.g. `image/x-fuji-raf`), falling back
+    /// to `application/octet-stream` for unknown raw extensions. Developed files report
+    /// `image/png` for a `.png` extension and `image/jpeg` otherwise. Other (sidecar) files
+    /// report `application/rdf+xml` for a `.xmp` extension and `text/xml` otherwise.
+    pub fn mime_type(&self, image_type: PhotoType) -> &'static str {
+        match image_type {
+            PhotoType::Raw => mime_for_extension(&self.raw_ext).unwrap_or("application/octet-stream"),
+            PhotoType::Img => match self.img_ext.to_lowercase().as_str() {
+                "png" => "image/png",
+                _ => "image/jpeg",
+            },
+            PhotoType::Other => match self.other_ext.to_lowercase().as_str() {
+                "xmp" => "application/rdf+xml",
+                _ => "text/xml",
+            },
+        }
+    }
+
+    /// Produces a preview image for this photo, suitable for gallery/grid UIs.
+    ///
+    /// # Description
+    ///
+    /// Prefers the JPEG preview embedded in the raw file, since most vendor raw containers
+    /// carry a full-size or reduced JPEG alongside the sensor data, and falls back to the
+    /// developed JPEG when no raw file is present (or it carries no embedded preview).
+    ///
+    /// Without the `raw-decode` feature, the embedded (or developed) JPEG bytes are returned
+    /// as-is and `max_edge` is ignored. With `raw-decode` enabled, the preview is decoded and
+    /// scaled down so that its longest edge does not exceed `max_edge` pixels, then
+    /// re-encoded as JPEG; this pulls in a full image decode/encode pipeline, so it is opt-in.
+    ///
+    /// Returns an `std::io::ErrorKind::NotFound` error when neither a raw nor a developed file
+    /// is present for this photo.
+    pub fn thumbnail(&self, max_edge: u32) -> std::io::Result<Vec<u8>> {
+        if self.types_found.hash_raw {
+            let (path, _) = self.component_path(&PhotoType::Raw);
+            let data = std::fs::read(path)?;
+            if let Some(preview) = Self::find_embedded_jpeg(&data) {
+                return Ok(Self::scale_preview(preview, max_edge));
+            }
+        }
+
+        if self.types_found.hash_img {
+            let (path, _) = self.component_path(&PhotoType::Img);
+            let data = std::fs::read(path)?;
+            return Ok(Self::scale_preview(data, max_edge));
+        }
+
+        Err(std::io::Error::from(std::io::ErrorKind::NotFound))
+    }
+
+    /// Scans `data` for the first complete JPEG (`FF D8 ... FF D9`) embedded in it.
+    fn find_embedded_jpeg(data: &[u8]) -> Option<Vec<u8>> {
+        let start = data.windows(2).position(|w| w == [0xFF, 0xD8])?;
+        let end = data[start..].windows(2).position(|w| w == [0xFF, 0xD9])?;
+
+        Some(data[start..start + end + 2].to_vec())
+    }
+
+    /// Scales `bytes` down so its longest edge does not exceed `max_edge`, when the
+    /// `raw-decode` feature is enabled; otherwise returns `bytes` unchanged.
+    #[cfg(feature = "raw-decode")]
+    fn scale_preview(bytes: Vec<u8>, max_edge: u32) -> Vec<u8> {
+        let Ok(img) = image::load_from_memory(&bytes) else {
+            return bytes;
+        };
+
+        let resized = if img.width() > max_edge || img.height() > max_edge {
+            img.resize(max_edge, max_edge, image::imageops::FilterType::Lanczos3)
         } else {
-            std::fs::remove_file(filepath)?;
-            Ok(0)
+            img
+        };
+        let mut out = Vec::new();
+        if resized
+            .write_to(&mut std::io::Cursor::new(&mut out), image::ImageFormat::Jpeg)
+            .is_ok()
+        {
+            out
+        } else {
+            bytes
         }
     }
 
-    // pub fn move(&mut self) -> std::io::Result<()> {
+    #[cfg(not(feature = "raw-decode"))]
+    fn scale_preview(bytes: Vec<u8>, _max_edge: u32) -> Vec<u8> {
+        bytes
+    }
+
+    /// Moves every present component of this photo to a new directory and/or stem name.
+    ///
+    /// # Description
+    ///
+    /// Moves the raw, developed and other components whose `types_found` flag is set,
+    /// keeping the bundle intact. `new_dir` defaults to the photo's current directory and
+    /// `new_stem` defaults to its current stem when `None` is passed. On success, `self.name`
+    /// is updated to the new directory/stem and the list of moved file paths is returned, in
+    /// raw/developed/other order.
+    ///
+    /// Each component is moved with `std::fs::rename`; when that fails because source and
+    /// destination live on different file systems (`EXDEV`), it falls back to copying the file
+    /// to the destination and then removing the original. On the first component that fails to
+    /// move, the error is returned immediately and any components already moved are left at
+    /// their new location.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use photo_lib::*;
+    /// use std::path::Path;
+    ///
+    /// let mut image = PhotoFile::new("/photos/myname", Some("dng"), Some("jpg"), None);
+    /// image.hash_raw(Some(true));
+    /// let moved = image.relocate(Some(Path::new("/photos/2024")), None).unwrap();
+    /// ```
+    pub fn relocate(
+        &mut self,
+        new_dir: Option<&Path>,
+        new_stem: Option<&str>,
+    ) -> std::io::Result<Vec<PathBuf>> {
+        let current = Path::new(&self.name);
+
+        let dir = match new_dir {
+            Some(dir) => dir.to_path_buf(),
+            None => current.parent().map(Path::to_path_buf).unwrap_or_default(),
+        };
+
+        let stem = match new_stem {
+            Some(stem) => String::from(stem),
+            None => current
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| self.name.clone()),
+        };
+
+        let destination = dir.join(stem);
+        let mut moved = Vec::new();
+
+        for image_type in [PhotoType::Raw, PhotoType::Img, PhotoType::Other] {
+            let (old_path, exists) = self.component_path(&image_type);
+
+            if !exists {
+                continue;
+            }
 
-    // }
+            let ext = match image_type {
+                PhotoType::Raw => self.raw_ext.as_str(),
+                PhotoType::Img => self.img_ext.as_str(),
+                PhotoType::Other => self.other_ext.as_str(),
+            };
 
+            let mut new_path = destination.clone().into_os_string();
+            new_path.push(".");
+            new_path.push(ext);
+            let new_path = PathBuf::from(new_path);
+
+            Self::move_file(&old_path, &new_path)?;
+            moved.push(new_path);
+        }
+
+        self.name = destination.to_string_lossy().into_owned();
+
+        Ok(moved)
+    }
+
+    /// Moves a single file, falling back to copy-then-delete on a cross-device error.
+    fn move_file(from: &str, to: &Path) -> std::io::Result<()> {
+        match std::fs::rename(from, to) {
+            Ok(()) => Ok(()),
+            Err(e) if is_cross_device_error(&e) => {
+                std::fs::copy(from, to)?;
+                std::fs::remove_file(from)?;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -221,39 +582,167 @@ mod tests {
     #[rstest]
     fn photo_file_hash_raw() {
         let mut pf = PhotoFile::new("test", None, None, None);
-        assert_eq!(pf.hash_raw(None), false);
+        assert!(!pf.hash_raw(None));
         pf.hash_raw(None);
-        assert_eq!(pf.hash_raw(None), false);
+        assert!(!pf.hash_raw(None));
         pf.hash_raw(Some(true));
-        assert_eq!(pf.hash_raw(None), true);
+        assert!(pf.hash_raw(None));
     }
 
     #[rstest]
     fn photo_file_hash_img() {
         let mut pf = PhotoFile::new("test", None, None, None);
-        assert_eq!(pf.hash_img(None), false);
+        assert!(!pf.hash_img(None));
         pf.hash_img(None);
-        assert_eq!(pf.hash_img(None), false);
+        assert!(!pf.hash_img(None));
         pf.hash_img(Some(true));
-        assert_eq!(pf.hash_img(None), true);
+        assert!(pf.hash_img(None));
     }
 
     #[rstest]
     fn photo_file_hash_other() {
         let mut pf = PhotoFile::new("test", None, None, None);
-        assert_eq!(pf.hash_other(None), false);
+        assert!(!pf.hash_other(None));
         pf.hash_other(None);
-        assert_eq!(pf.hash_other(None), false);
+        assert!(!pf.hash_other(None));
         pf.hash_other(Some(true));
-        assert_eq!(pf.hash_other(None), true);
+        assert!(pf.hash_other(None));
+    }
+
+    #[rstest]
+    fn photo_file_new_vendor_from_raw_ext() {
+        let pf = PhotoFile::new("test", None, None, None);
+        assert_eq!(pf.vendor(), Some("Fujifilm"));
+        let pf2 = PhotoFile::new("test2", Some("nef"), None, None);
+        assert_eq!(pf2.vendor(), Some("Nikon"));
+        let pf3 = PhotoFile::new("test3", Some("unknown"), None, None);
+        assert_eq!(pf3.vendor(), None);
+    }
+
+    #[rstest]
+    fn photo_file_from_raw_path() {
+        let mut path = std::env::temp_dir();
+        path.push(format!("photo_lib_test_from_raw_path_{}", std::process::id()));
+        let stem = path.to_str().unwrap().to_string();
+        std::fs::write(format!("{stem}.RAF"), b"raw bytes").unwrap();
+
+        let mut pf = PhotoFile::from_raw_path(format!("{stem}.RAF")).unwrap();
+        assert_eq!(pf.name, stem);
+        assert_eq!(pf.vendor(), Some("Fujifilm"));
+        assert!(pf.hash_raw(None));
+        assert!(pf.digest(PhotoType::Raw).is_ok());
+
+        assert!(PhotoFile::from_raw_path(format!("{stem}.JPG")).is_none());
+
+        pf.clear(PhotoType::Raw).unwrap();
+        assert!(!std::path::Path::new(&format!("{stem}.RAF")).exists());
+    }
+
+    #[rstest]
+    fn photo_file_mark_found_case_insensitive() {
+        let mut pf = PhotoFile::new("test", None, None, None);
+        assert!(pf.mark_found("raf").is_some());
+        assert!(pf.hash_raw(None));
+        assert_eq!(pf.raw_ext, "raf");
+    }
+
+    #[rstest]
+    fn photo_file_digest() {
+        let mut path = std::env::temp_dir();
+        path.push(format!("photo_lib_test_digest_{}", std::process::id()));
+        let stem = path.to_str().unwrap().to_string();
+        std::fs::write(format!("{stem}.RAF"), b"hello raw file").unwrap();
+
+        let mut pf = PhotoFile::new(&stem, None, None, None);
+        pf.hash_raw(Some(true));
+
+        let digest = pf.digest(PhotoType::Raw).unwrap();
+        assert_eq!(
+            digest,
+            "fe5f0ca36b46d3c3f4795c6f93693a313a23dcc13872daf1c81ccd9f8d97bc7f"
+        );
+
+        std::fs::remove_file(format!("{stem}.RAF")).unwrap();
+    }
+
+    #[rstest]
+    fn photo_file_digest_not_found() {
+        let pf = PhotoFile::new("missing_file_xyz", None, None, None);
+        assert!(pf.digest(PhotoType::Raw).is_err());
+    }
+
+    #[rstest]
+    fn photo_file_thumbnail_extracts_embedded_preview() {
+        let mut path = std::env::temp_dir();
+        path.push(format!("photo_lib_test_thumb_{}", std::process::id()));
+        let stem = path.to_str().unwrap().to_string();
+
+        let mut raw_bytes = b"FUJIFILMCCD-RAW sensor data...".to_vec();
+        let embedded_jpeg: &[u8] = &[0xFF, 0xD8, 0xFF, 0xE0, b'p', b'r', b'e', b'v', 0xFF, 0xD9];
+        raw_bytes.extend_from_slice(embedded_jpeg);
+        raw_bytes.extend_from_slice(b"...more sensor data");
+        std::fs::write(format!("{stem}.RAF"), &raw_bytes).unwrap();
+
+        let mut pf = PhotoFile::new(&stem, None, None, None);
+        pf.hash_raw(Some(true));
+
+        let thumb = pf.thumbnail(256).unwrap();
+        assert_eq!(thumb, embedded_jpeg);
+
+        std::fs::remove_file(format!("{stem}.RAF")).unwrap();
+    }
+
+    #[rstest]
+    fn photo_file_thumbnail_not_found() {
+        let pf = PhotoFile::new("missing_thumb_xyz", None, None, None);
+        assert!(pf.thumbnail(256).is_err());
+    }
+
+    #[rstest]
+    fn photo_file_mime_type() {
+        let pf = PhotoFile::new("test", None, None, None);
+        assert_eq!(pf.mime_type(PhotoType::Raw), "image/x-fuji-raf");
+        assert_eq!(pf.mime_type(PhotoType::Img), "image/jpeg");
+        assert_eq!(pf.mime_type(PhotoType::Other), "application/rdf+xml");
+
+        let pf2 = PhotoFile::new("test2", Some("unknown"), Some("png"), Some("json"));
+        assert_eq!(pf2.mime_type(PhotoType::Raw), "application/octet-stream");
+        assert_eq!(pf2.mime_type(PhotoType::Img), "image/png");
+        assert_eq!(pf2.mime_type(PhotoType::Other), "text/xml");
+    }
+
+    #[rstest]
+    fn photo_file_relocate_moves_present_components() {
+        let src_dir = std::env::temp_dir().join(format!("photo_lib_test_relocate_src_{}", std::process::id()));
+        let dst_dir = std::env::temp_dir().join(format!("photo_lib_test_relocate_dst_{}", std::process::id()));
+        std::fs::create_dir_all(&src_dir).unwrap();
+        std::fs::create_dir_all(&dst_dir).unwrap();
+
+        let stem = src_dir.join("DSCF1022").to_str().unwrap().to_string();
+        std::fs::write(format!("{stem}.RAF"), b"raw").unwrap();
+        std::fs::write(format!("{stem}.xmp"), b"sidecar").unwrap();
+
+        let mut pf = PhotoFile::new(&stem, None, None, None);
+        pf.hash_raw(Some(true));
+        pf.hash_other(Some(true));
+
+        let moved = pf.relocate(Some(&dst_dir), Some("DSCF1022")).unwrap();
+
+        assert_eq!(moved.len(), 2);
+        assert!(dst_dir.join("DSCF1022.RAF").exists());
+        assert!(dst_dir.join("DSCF1022.xmp").exists());
+        assert!(!std::path::Path::new(&format!("{stem}.RAF")).exists());
+
+        std::fs::remove_dir_all(&src_dir).unwrap();
+        std::fs::remove_dir_all(&dst_dir).unwrap();
     }
 
     #[rstest]
     fn photo_file_is_developed() {
         let mut pf = PhotoFile::new("test", None, None, None);
-        assert_eq!(pf.is_developed(), false);
+        assert!(!pf.is_developed());
         pf.hash_raw(Some(true));
         pf.hash_img(Some(true));
-        assert_eq!(pf.is_developed(), true);
+        assert!(pf.is_developed());
     }
 }
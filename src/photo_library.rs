@@ -0,0 +1,228 @@
+use super::{PhotoFile, PhotoType};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// A collection of [`PhotoFile`] bundles discovered in a directory.
+///
+/// # Description
+///
+/// A folder with pictures usually holds several files that belong to the same photo: the
+/// _raw_ file produced by the camera, an optional _developed_ file and optional sidecar
+/// files created by 3rd party software. [`PhotoLibrary::scan`] walks a directory, groups
+/// those files by their stem name and returns one [`PhotoFile`] per logical photo.
+pub struct PhotoLibrary {
+    photos: Vec<PhotoFile>,
+}
+
+impl PhotoLibrary {
+    /// Scans `path` and builds a `PhotoLibrary` out of the files found in it.
+    ///
+    /// # Description
+    ///
+    /// Every regular file directly under `path` is grouped with the other files that share
+    /// its stem (the file name without the extension). For example, `DSCF1022.RAF`,
+    /// `DSCF1022.JPG` and `DSCF1022.xmp` collapse into a single [`PhotoFile`], with
+    /// `types_found` set according to the extensions that were found. Each file's extension is
+    /// classified against the raw extension registry, which recognises any vendor (not just
+    /// Fujifilm's `RAF`), plus the common developed-image and sidecar extensions, so a folder
+    /// mixing `.CR2`, `.NEF`, `.jpeg` and `.xmp` files is grouped correctly.
+    ///
+    /// Sub-directories are not traversed.
+    ///
+    /// # Example of use
+    ///
+    /// ```rust,no_run
+    /// use photo_lib::PhotoLibrary;
+    ///
+    /// let library = PhotoLibrary::scan("/home/user/pictures").unwrap();
+    /// let developed = library.iter().filter(|p| p.is_developed()).count();
+    /// println!("{developed} developed photos");
+    /// ```
+    pub fn scan<P: AsRef<Path>>(path: P) -> std::io::Result<PhotoLibrary> {
+        let mut bundles: HashMap<String, PhotoFile> = HashMap::new();
+
+        for entry in std::fs::read_dir(path)? {
+            let entry = entry?;
+            let file_path = entry.path();
+
+            if !file_path.is_file() {
+                continue;
+            }
+
+            let stem = match file_path.file_stem().and_then(|s| s.to_str()) {
+                Some(stem) => stem.to_string(),
+                None => continue,
+            };
+
+            let ext = match file_path.extension().and_then(|s| s.to_str()) {
+                Some(ext) => ext.to_string(),
+                None => continue,
+            };
+
+            // Keep the directory in the bundle's name so operations like `digest` and
+            // `clear`, which resolve a component's path from the bundle's name, still work
+            // regardless of the process's current directory.
+            let full_stem = file_path.with_extension("").to_string_lossy().to_string();
+
+            let photo = bundles
+                .entry(stem)
+                .or_insert_with(|| PhotoFile::new(&full_stem, None, None, None));
+
+            photo.mark_found(&ext);
+        }
+
+        Ok(PhotoLibrary {
+            photos: bundles.into_values().collect(),
+        })
+    }
+
+    /// Returns an iterator over the photo bundles held by this library.
+    pub fn iter(&self) -> std::slice::Iter<'_, PhotoFile> {
+        self.photos.iter()
+    }
+
+    /// Returns how many photo bundles this library holds.
+    pub fn len(&self) -> usize {
+        self.photos.len()
+    }
+
+    /// Returns `true` when this library holds no photo bundles.
+    pub fn is_empty(&self) -> bool {
+        self.photos.is_empty()
+    }
+
+    /// Groups photo bundles that share the same file content.
+    ///
+    /// # Description
+    ///
+    /// Computes the SHA-256 digest (see [`PhotoFile::digest`]) of each bundle's raw component,
+    /// falling back to its developed component when no raw file was found, and groups bundles
+    /// whose digest matches. Bundles with neither component present are skipped. Only digests
+    /// shared by two or more bundles are returned, since those are the ones that indicate the
+    /// same shot was imported more than once under a different name.
+    pub fn find_duplicates(&self) -> Vec<Vec<&PhotoFile>> {
+        let mut by_digest: HashMap<String, Vec<&PhotoFile>> = HashMap::new();
+
+        for photo in &self.photos {
+            let digest = photo
+                .digest(PhotoType::Raw)
+                .or_else(|_| photo.digest(PhotoType::Img));
+
+            if let Ok(digest) = digest {
+                by_digest.entry(digest).or_default().push(photo);
+            }
+        }
+
+        by_digest
+            .into_values()
+            .filter(|group| group.len() > 1)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+    use std::fs::File;
+
+    fn unique_tmp_dir(name: &str) -> std::path::PathBuf {
+        let mut dir = std::env::temp_dir();
+        dir.push(format!("photo_lib_test_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[rstest]
+    fn photo_library_scan_groups_by_stem() {
+        let dir = unique_tmp_dir("scan_groups_by_stem");
+
+        File::create(dir.join("DSCF1022.RAF")).unwrap();
+        File::create(dir.join("DSCF1022.JPG")).unwrap();
+        File::create(dir.join("DSCF1022.xmp")).unwrap();
+        File::create(dir.join("DSCF1023.RAF")).unwrap();
+
+        let library = PhotoLibrary::scan(&dir).unwrap();
+
+        assert_eq!(library.len(), 2);
+        assert_eq!(library.iter().filter(|p| p.is_developed()).count(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[rstest]
+    fn photo_library_scan_recognizes_mixed_vendors() {
+        let dir = unique_tmp_dir("scan_recognizes_mixed_vendors");
+
+        File::create(dir.join("IMG_0001.CR2")).unwrap();
+        File::create(dir.join("IMG_0001.jpeg")).unwrap();
+        File::create(dir.join("DSC_0002.NEF")).unwrap();
+        File::create(dir.join("DSC_0002.xmp")).unwrap();
+        File::create(dir.join("P1030003.ARW")).unwrap();
+
+        let library = PhotoLibrary::scan(&dir).unwrap();
+
+        assert_eq!(library.len(), 3);
+        assert_eq!(library.iter().filter(|p| p.is_developed()).count(), 1);
+        assert_eq!(
+            library
+                .iter()
+                .filter(|p| p.vendor() == Some("Canon"))
+                .count(),
+            1
+        );
+        assert_eq!(
+            library
+                .iter()
+                .filter(|p| p.vendor() == Some("Sony"))
+                .count(),
+            1
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[rstest]
+    fn photo_library_find_duplicates() {
+        let dir = unique_tmp_dir("find_duplicates");
+
+        std::fs::write(dir.join("DSCF1022.RAF"), b"same content").unwrap();
+        std::fs::write(dir.join("DSCF1099.RAF"), b"same content").unwrap();
+        std::fs::write(dir.join("DSCF1100.RAF"), b"different content").unwrap();
+
+        let library = PhotoLibrary::scan(&dir).unwrap();
+        let duplicates = library.find_duplicates();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].len(), 2);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[rstest]
+    fn photo_library_find_duplicates_across_vendors() {
+        let dir = unique_tmp_dir("find_duplicates_across_vendors");
+
+        std::fs::write(dir.join("IMG_0001.CR2"), b"same content").unwrap();
+        std::fs::write(dir.join("DSC_0099.NEF"), b"same content").unwrap();
+        std::fs::write(dir.join("DSC_0002.jpeg"), b"different content").unwrap();
+
+        let library = PhotoLibrary::scan(&dir).unwrap();
+        let duplicates = library.find_duplicates();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].len(), 2);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[rstest]
+    fn photo_library_scan_empty_dir() {
+        let dir = unique_tmp_dir("scan_empty_dir");
+
+        let library = PhotoLibrary::scan(&dir).unwrap();
+
+        assert!(library.is_empty());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
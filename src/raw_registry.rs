@@ -0,0 +1,71 @@
+/// Known raw file extensions paired with the camera vendor that produces them and the MIME
+/// type used to report that component (see [`crate::PhotoFile::mime_type`]).
+///
+/// Extensions are stored lower-case; lookups should normalise their input accordingly.
+pub(crate) const RAW_EXTENSIONS: &[(&str, &str, &str)] = &[
+    ("3fr", "Hasselblad", "image/x-hasselblad-3fr"),
+    ("arw", "Sony", "image/x-sony-arw"),
+    ("cr2", "Canon", "image/x-canon-cr2"),
+    ("cr3", "Canon", "image/x-canon-cr3"),
+    ("dng", "Adobe", "image/x-adobe-dng"),
+    ("erf", "Epson", "image/x-epson-erf"),
+    ("iiq", "Phase One", "image/x-phaseone-iiq"),
+    ("mrw", "Minolta", "image/x-minolta-mrw"),
+    ("nef", "Nikon", "image/x-nikon-nef"),
+    ("nrw", "Nikon", "image/x-nikon-nrw"),
+    ("orf", "Olympus", "image/x-olympus-orf"),
+    ("pef", "Pentax", "image/x-pentax-pef"),
+    ("raf", "Fujifilm", "image/x-fuji-raf"),
+    ("rw2", "Panasonic", "image/x-panasonic-rw2"),
+    ("srw", "Samsung", "image/x-samsung-srw"),
+];
+
+/// Looks up the vendor that manufactures cameras producing raw files with extension `ext`.
+///
+/// The comparison is case-insensitive, so `"RAF"` and `"raf"` both resolve to `"Fujifilm"`.
+pub(crate) fn vendor_for_extension(ext: &str) -> Option<&'static str> {
+    RAW_EXTENSIONS
+        .iter()
+        .find(|(raw_ext, _, _)| raw_ext.eq_ignore_ascii_case(ext))
+        .map(|(_, vendor, _)| *vendor)
+}
+
+/// Looks up the MIME type for a raw file with extension `ext`.
+///
+/// The comparison is case-insensitive, so `"RAF"` and `"raf"` both resolve to
+/// `"image/x-fuji-raf"`.
+pub(crate) fn mime_for_extension(ext: &str) -> Option<&'static str> {
+    RAW_EXTENSIONS
+        .iter()
+        .find(|(raw_ext, _, _)| raw_ext.eq_ignore_ascii_case(ext))
+        .map(|(_, _, mime)| *mime)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    fn vendor_for_extension_known_case_insensitive() {
+        assert_eq!(vendor_for_extension("RAF"), Some("Fujifilm"));
+        assert_eq!(vendor_for_extension("raf"), Some("Fujifilm"));
+        assert_eq!(vendor_for_extension("NEF"), Some("Nikon"));
+    }
+
+    #[rstest]
+    fn vendor_for_extension_unknown() {
+        assert_eq!(vendor_for_extension("jpg"), None);
+    }
+
+    #[rstest]
+    fn mime_for_extension_known_case_insensitive() {
+        assert_eq!(mime_for_extension("RAF"), Some("image/x-fuji-raf"));
+        assert_eq!(mime_for_extension("dng"), Some("image/x-adobe-dng"));
+    }
+
+    #[rstest]
+    fn mime_for_extension_unknown() {
+        assert_eq!(mime_for_extension("jpg"), None);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::raw_registry::vendor_for_extension;
+use crate::PhotoType;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// JPEG files start with this 3-byte marker.
+const JPEG_SIGNATURE: &[u8] = &[0xFF, 0xD8, 0xFF];
+/// PNG files start with this 8-byte marker.
+const PNG_SIGNATURE: &[u8] = &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A];
+/// Little-endian TIFF (and most TIFF-based raw containers) start with this marker.
+const TIFF_LE_SIGNATURE: &[u8] = &[0x49, 0x49, 0x2A, 0x00];
+/// Big-endian TIFF starts with this marker.
+const TIFF_BE_SIGNATURE: &[u8] = &[0x4D, 0x4D, 0x00, 0x2A];
+/// Fujifilm RAF files start with this ASCII marker.
+const RAF_SIGNATURE: &[u8] = b"FUJIFILMCCD-RAW";
+
+/// Number of leading bytes read from a file to match it against a signature.
+const SIGNATURE_BUF_LEN: usize = 16;
+
+impl PhotoType {
+    /// Identifies the type of a file by inspecting its leading bytes.
+    ///
+    /// # Description
+    ///
+    /// Reads the first bytes of `path` and compares them against a table of known file
+    /// signatures ("magic bytes"):
+    /// - `FUJIFILMCCD-RAW`: Fujifilm RAF, reported as [`PhotoType::Raw`] with vendor
+    ///   `Some("Fujifilm")`.
+    /// - `49 49 2A 00` / `4D 4D 00 2A`: TIFF, the container used by most other raw formats,
+    ///   reported as [`PhotoType::Raw`].
+    /// - `FF D8 FF`: JPEG, reported as [`PhotoType::Img`].
+    /// - `89 50 4E 47 0D 0A 1A 0A`: PNG, reported as [`PhotoType::Img`].
+    ///
+    /// When none of the signatures match (for example, the file is empty, truncated, or of an
+    /// unrecognised format), falls back to classifying `path` by its extension: `jpg`/`jpeg`
+    /// is reported as [`PhotoType::Img`], an extension in the raw extension registry as
+    /// [`PhotoType::Raw`] with its vendor, and anything else as [`PhotoType::Other`].
+    ///
+    /// Returns the detected [`PhotoType`] together with an optional vendor tag.
+    pub fn identify<P: AsRef<Path>>(path: P) -> io::Result<(PhotoType, Option<&'static str>)> {
+        let path = path.as_ref();
+        let mut file = File::open(path)?;
+        let mut buf = [0u8; SIGNATURE_BUF_LEN];
+        let read = file.read(&mut buf)?;
+        let head = &buf[..read];
+
+        if head.starts_with(RAF_SIGNATURE) {
+            return Ok((PhotoType::Raw, Some("Fujifilm")));
+        }
+
+        if head.starts_with(TIFF_LE_SIGNATURE) || head.starts_with(TIFF_BE_SIGNATURE) {
+            let vendor = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .and_then(vendor_for_extension);
+            return Ok((PhotoType::Raw, vendor));
+        }
+
+        if head.starts_with(JPEG_SIGNATURE) || head.starts_with(PNG_SIGNATURE) {
+            return Ok((PhotoType::Img, None));
+        }
+
+        Ok(Self::identify_by_extension(path))
+    }
+
+    /// Classifies `path` by its extension, used as a fallback by [`PhotoType::identify`] when
+    /// no signature matches.
+    fn identify_by_extension(path: &Path) -> (PhotoType, Option<&'static str>) {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("jpg") || ext.eq_ignore_ascii_case("jpeg") => {
+                (PhotoType::Img, None)
+            }
+            Some(ext) => match vendor_for_extension(ext) {
+                Some(vendor) => (PhotoType::Raw, Some(vendor)),
+                None => (PhotoType::Other, None),
+            },
+            None => (PhotoType::Other, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+    use std::io::Write;
+
+    fn write_tmp_file(name: &str, contents: &[u8]) -> std::path::PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!("photo_lib_test_sig_{}_{}", name, std::process::id()));
+        let mut file = File::create(&path).unwrap();
+        file.write_all(contents).unwrap();
+        path
+    }
+
+    #[rstest]
+    fn identify_jpeg_signature() {
+        let path = write_tmp_file("jpeg", &[0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10]);
+        let (kind, vendor) = PhotoType::identify(&path).unwrap();
+        assert!(matches!(kind, PhotoType::Img));
+        assert_eq!(vendor, None);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[rstest]
+    fn identify_raf_signature() {
+        let path = write_tmp_file("raf", b"FUJIFILMCCD-RAW extra bytes here");
+        let (kind, vendor) = PhotoType::identify(&path).unwrap();
+        assert!(matches!(kind, PhotoType::Raw));
+        assert_eq!(vendor, Some("Fujifilm"));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[rstest]
+    fn identify_falls_back_to_extension() {
+        let mut path = std::env::temp_dir();
+        path.push(format!(
+            "photo_lib_test_sig_fallback_{}.cr2",
+            std::process::id()
+        ));
+        std::fs::write(&path, b"not a real signature").unwrap();
+        let (kind, vendor) = PhotoType::identify(&path).unwrap();
+        assert!(matches!(kind, PhotoType::Raw));
+        assert_eq!(vendor, Some("Canon"));
+        std::fs::remove_file(&path).unwrap();
+    }
+}